@@ -0,0 +1,26 @@
+// Fixture for go_to_implementation: a trait with two concrete impls, so
+// the tool should return both impl locations rather than the trait itself.
+trait Greeter {
+    fn greet(&self) -> String;
+}
+
+struct English;
+struct Japanese;
+
+impl Greeter for English {
+    fn greet(&self) -> String {
+        "Hello!".to_string()
+    }
+}
+
+impl Greeter for Japanese {
+    fn greet(&self) -> String {
+        "こんにちは!".to_string()
+    }
+}
+
+fn greet_all(greeters: Vec<Box<dyn Greeter>>) {
+    for greeter in greeters {
+        println!("{}", greeter.greet());
+    }
+}